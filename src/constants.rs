@@ -0,0 +1,7 @@
+//! Shared constants for the crate.
+
+/// EIP-2718 transaction type byte for EIP-2930 (access list) transactions.
+pub const EIP_2930_TYPE: u8 = 0x01;
+
+/// EIP-2718 transaction type byte for EIP-1559 (dynamic fee) transactions.
+pub const EIP_1559_TYPE: u8 = 0x02;
@@ -0,0 +1,90 @@
+use rlp::RlpStream;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::constants::EIP_2930_TYPE;
+
+use super::types::{AccessList, Address, Signature};
+
+/// An EIP-2930 transaction, encoded as EIP-2718 envelope type `0x01`.
+///
+/// Like a legacy transaction, it is priced with a single `gas_price`, but it
+/// additionally carries an `access_list` of addresses and storage slots to
+/// pre-warm.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct Eip2930Transaction {
+    pub chain_id: u64,
+    pub nonce: u64,
+    pub gas_price: u128,
+    pub gas_limit: u128,
+    pub to: Option<Address>,
+    pub value: u128,
+    pub input: Vec<u8>,
+    pub access_list: AccessList,
+}
+
+impl Eip2930Transaction {
+    pub fn build_for_signing(&self) -> Vec<u8> {
+        let mut rlp_stream = RlpStream::new();
+
+        rlp_stream.append(&EIP_2930_TYPE);
+
+        rlp_stream.begin_unbounded_list();
+
+        self.encode_fields(&mut rlp_stream);
+
+        rlp_stream.finalize_unbounded_list();
+
+        rlp_stream.out().to_vec()
+    }
+
+    pub fn build_with_signature(&self, signature: &Signature) -> Vec<u8> {
+        let mut rlp_stream = RlpStream::new();
+
+        rlp_stream.append(&EIP_2930_TYPE);
+
+        rlp_stream.begin_unbounded_list();
+
+        self.encode_fields(&mut rlp_stream);
+
+        rlp_stream.append(&signature.v);
+        rlp_stream.append(&signature.r);
+        rlp_stream.append(&signature.s);
+
+        rlp_stream.finalize_unbounded_list();
+
+        rlp_stream.out().to_vec()
+    }
+
+    fn encode_fields(&self, rlp_stream: &mut RlpStream) {
+        let to: Vec<u8> = self.to.map_or(vec![], |to| to.to_vec());
+        let access_list = self.access_list.clone();
+
+        rlp_stream.append(&self.chain_id);
+        rlp_stream.append(&self.nonce);
+        rlp_stream.append(&self.gas_price);
+        rlp_stream.append(&self.gas_limit);
+        rlp_stream.append(&to);
+        rlp_stream.append(&self.value);
+        rlp_stream.append(&self.input);
+
+        // Write access list.
+        {
+            rlp_stream.begin_unbounded_list();
+            for access in access_list {
+                rlp_stream.begin_unbounded_list();
+                rlp_stream.append(&access.0.to_vec());
+                // Append list of storage keys.
+                {
+                    rlp_stream.begin_unbounded_list();
+                    for storage_key in access.1 {
+                        rlp_stream.append(&storage_key.to_vec());
+                    }
+                    rlp_stream.finalize_unbounded_list();
+                }
+                rlp_stream.finalize_unbounded_list();
+            }
+            rlp_stream.finalize_unbounded_list();
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use thiserror::Error;
+
+/// Errors that can occur while decoding a raw transaction.
+#[derive(Debug, Error)]
+pub enum DecodeError {
+    #[error("transaction bytes are empty")]
+    Empty,
+    #[error("unknown transaction type byte: {0:#x}")]
+    UnknownType(u8),
+    #[error("field has an unexpected length")]
+    InvalidLength,
+    #[error("invalid RLP encoding: {0}")]
+    Rlp(#[from] rlp::DecoderError),
+}
+
+/// Errors that can occur while recovering the signer of a transaction.
+#[derive(Debug, Error)]
+pub enum RecoverError {
+    #[error("signature r/s must be at most 32 bytes")]
+    InvalidSignatureLength,
+    #[error("signature s value is malleable (greater than secp256k1n/2)")]
+    HighS,
+    #[error("invalid recovery id: {0}")]
+    InvalidRecoveryId(u64),
+    #[error("signature recovery failed: {0}")]
+    Secp256k1(#[from] secp256k1::Error),
+}
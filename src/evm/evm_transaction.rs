@@ -10,7 +10,7 @@ use super::utils::parse_eth_address;
 ///
 /// ###### Example:
 ///
-/// ```rust
+/// ```rust,ignore
 /// let nonce: u64 = 0;
 /// let to: Address = address!("d8dA6BF26964aF9D7eEd9e03E53415D37aA96045");
 /// let value = 10000000000000000u128; // 0.01 ETH
@@ -32,7 +32,7 @@ use super::utils::parse_eth_address;
 /// };
 /// ```
 ///
-#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub struct EVMTransaction {
     pub chain_id: u64,
     pub nonce: u64,
@@ -149,8 +149,13 @@ impl EVMTransaction {
         let input =
             hex::decode(input.strip_prefix("0x").unwrap_or("")).expect("input should be hex");
 
-        // TODO: Implement access list
-        // let access_list = v["accessList"].as_str().unwrap_or_default().to_string();
+        let access_list = match v["accessList"].as_array() {
+            Some(list) => list
+                .iter()
+                .map(parse_access_list_entry)
+                .collect::<Result<Vec<_>, _>>()?,
+            None => Vec::new(),
+        };
 
         Ok(Self {
             chain_id,
@@ -161,11 +166,43 @@ impl EVMTransaction {
             gas_limit,
             max_fee_per_gas,
             max_priority_fee_per_gas,
-            access_list: vec![],
+            access_list,
         })
     }
 }
 
+fn parse_access_list_entry(
+    entry: &serde_json::Value,
+) -> Result<(Address, Vec<[u8; 32]>), serde_json::Error> {
+    use serde::de::Error;
+
+    let address = entry["address"]
+        .as_str()
+        .ok_or_else(|| serde_json::Error::custom("accessList address should be provided"))?;
+    let address = hex::decode(address.strip_prefix("0x").unwrap_or(address))
+        .map_err(|e| serde_json::Error::custom(format!("accessList address should be hex: {e}")))?;
+    let address: Address = address
+        .try_into()
+        .map_err(|_| serde_json::Error::custom("accessList address should be 20 bytes"))?;
+
+    let storage_keys = entry["storageKeys"]
+        .as_array()
+        .ok_or_else(|| serde_json::Error::custom("accessList storageKeys should be provided"))?
+        .iter()
+        .map(|key| {
+            let key = key
+                .as_str()
+                .ok_or_else(|| serde_json::Error::custom("storage key should be a string"))?;
+            let key = hex::decode(key.strip_prefix("0x").unwrap_or(key))
+                .map_err(|e| serde_json::Error::custom(format!("storage key should be hex: {e}")))?;
+            key.try_into()
+                .map_err(|_| serde_json::Error::custom("storage key should be 32 bytes"))
+        })
+        .collect::<Result<Vec<[u8; 32]>, serde_json::Error>>()?;
+
+    Ok((address, storage_keys))
+}
+
 fn parse_u64(value: &str) -> Result<u64, std::num::ParseIntError> {
     value.strip_prefix("0x").map_or_else(
         || value.parse::<u64>(),
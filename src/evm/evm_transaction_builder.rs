@@ -3,6 +3,7 @@ use crate::transaction_builder::TxBuilder;
 
 use super::{
     evm_transaction::EVMTransaction,
+    legacy_transaction::LegacyTransaction,
     types::{AccessList, Address},
 };
 
@@ -13,6 +14,7 @@ pub struct EVMTransactionBuilder {
     value: Option<u128>,
     input: Option<Vec<u8>>,
     gas_limit: Option<u128>,
+    gas_price: Option<u128>,
     max_fee_per_gas: Option<u128>,
     max_priority_fee_per_gas: Option<u128>,
     access_list: Option<AccessList>,
@@ -26,6 +28,12 @@ impl Default for EVMTransactionBuilder {
 
 impl TxBuilder<EVMTransaction> for EVMTransactionBuilder {
     fn build(&self) -> EVMTransaction {
+        assert!(
+            self.gas_price.is_none(),
+            "gas_price is for legacy transactions; use max_fee_per_gas/\
+             max_priority_fee_per_gas for an EVMTransaction"
+        );
+
         EVMTransaction {
             chain_id: self.chain_id.expect("chain_id is mandatory"),
             nonce: self.nonce.expect("nonce is mandatory"),
@@ -40,6 +48,26 @@ impl TxBuilder<EVMTransaction> for EVMTransactionBuilder {
     }
 }
 
+impl TxBuilder<LegacyTransaction> for EVMTransactionBuilder {
+    fn build(&self) -> LegacyTransaction {
+        assert!(
+            self.max_fee_per_gas.is_none() && self.max_priority_fee_per_gas.is_none(),
+            "max_fee_per_gas/max_priority_fee_per_gas are for EIP-1559 transactions; \
+             use gas_price for a LegacyTransaction"
+        );
+
+        LegacyTransaction {
+            chain_id: self.chain_id.expect("chain_id is mandatory"),
+            nonce: self.nonce.expect("nonce is mandatory"),
+            gas_price: self.gas_price.expect("gas_price is mandatory"),
+            gas_limit: self.gas_limit.expect("gas_limit is mandatory"),
+            to: self.to,
+            value: self.value.unwrap_or_default(),
+            input: self.input.clone().unwrap_or_default(),
+        }
+    }
+}
+
 impl EVMTransactionBuilder {
     pub const fn new() -> Self {
         Self {
@@ -49,6 +77,7 @@ impl EVMTransactionBuilder {
             value: None,
             input: None,
             gas_limit: None,
+            gas_price: None,
             max_fee_per_gas: None,
             max_priority_fee_per_gas: None,
             access_list: None,
@@ -91,6 +120,13 @@ impl EVMTransactionBuilder {
         self
     }
 
+    /// Gas price of a legacy transaction. Mutually exclusive with the
+    /// EIP-1559 `max_fee_per_gas`/`max_priority_fee_per_gas` fields.
+    pub const fn gas_price(mut self, gas_price: u128) -> Self {
+        self.gas_price = Some(gas_price);
+        self
+    }
+
     /// Maximum fee per gas of the transaction.
     pub const fn max_fee_per_gas(mut self, max_fee_per_gas: u128) -> Self {
         self.max_fee_per_gas = Some(max_fee_per_gas);
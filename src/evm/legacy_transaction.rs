@@ -0,0 +1,76 @@
+use rlp::RlpStream;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::types::{Address, Signature};
+
+/// An untyped, pre-EIP-2718 transaction with EIP-155 replay protection.
+///
+/// Legacy transactions carry a `gas_price` instead of the EIP-1559 fee
+/// fields and are not prefixed with a transaction type byte, which is what
+/// older nodes and some L2s still expect.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct LegacyTransaction {
+    pub chain_id: u64,
+    pub nonce: u64,
+    pub gas_price: u128,
+    pub gas_limit: u128,
+    pub to: Option<Address>,
+    pub value: u128,
+    pub input: Vec<u8>,
+}
+
+impl LegacyTransaction {
+    /// Builds the EIP-155 signing payload: the transaction fields followed
+    /// by `chain_id, 0, 0` in place of `v, r, s`.
+    pub fn build_for_signing(&self) -> Vec<u8> {
+        let mut rlp_stream = RlpStream::new();
+
+        rlp_stream.begin_unbounded_list();
+
+        self.encode_fields(&mut rlp_stream);
+
+        rlp_stream.append(&self.chain_id);
+        rlp_stream.append(&0u8);
+        rlp_stream.append(&0u8);
+
+        rlp_stream.finalize_unbounded_list();
+
+        rlp_stream.out().to_vec()
+    }
+
+    /// Builds the final signed transaction. `signature.v` is expected to be
+    /// the raw 0/1 recovery id; it is folded into the EIP-155 `v` value
+    /// (`recovery_id + 35 + chain_id * 2`) before being appended.
+    pub fn build_with_signature(&self, signature: &Signature) -> Vec<u8> {
+        let mut rlp_stream = RlpStream::new();
+
+        rlp_stream.begin_unbounded_list();
+
+        self.encode_fields(&mut rlp_stream);
+
+        let v = self
+            .chain_id
+            .saturating_mul(2)
+            .saturating_add(35)
+            .saturating_add(signature.v);
+        rlp_stream.append(&v);
+        rlp_stream.append(&signature.r);
+        rlp_stream.append(&signature.s);
+
+        rlp_stream.finalize_unbounded_list();
+
+        rlp_stream.out().to_vec()
+    }
+
+    fn encode_fields(&self, rlp_stream: &mut RlpStream) {
+        let to: Vec<u8> = self.to.map_or(vec![], |to| to.to_vec());
+
+        rlp_stream.append(&self.nonce);
+        rlp_stream.append(&self.gas_price);
+        rlp_stream.append(&self.gas_limit);
+        rlp_stream.append(&to);
+        rlp_stream.append(&self.value);
+        rlp_stream.append(&self.input);
+    }
+}
@@ -0,0 +1,8 @@
+pub mod eip2930_transaction;
+pub mod errors;
+pub mod evm_transaction;
+pub mod evm_transaction_builder;
+pub mod legacy_transaction;
+pub mod typed_transaction;
+pub mod types;
+pub mod utils;
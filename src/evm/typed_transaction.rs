@@ -0,0 +1,381 @@
+use rlp::Rlp;
+use schemars::JsonSchema;
+use secp256k1::ecdsa::{RecoverableSignature, RecoveryId};
+use secp256k1::{Message, Secp256k1};
+use serde::{Deserialize, Serialize};
+
+use crate::constants::{EIP_1559_TYPE, EIP_2930_TYPE};
+
+use super::eip2930_transaction::Eip2930Transaction;
+use super::errors::{DecodeError, RecoverError};
+use super::evm_transaction::EVMTransaction;
+use super::legacy_transaction::LegacyTransaction;
+use super::types::{AccessList, Address, Signature};
+use super::utils::keccak256;
+
+/// Half of the secp256k1 curve order, used to reject malleable (high-`s`)
+/// signatures per EIP-2.
+const SECP256K1N_HALF: [u8; 32] = [
+    0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0x5d, 0x57, 0x6e, 0x73, 0x57, 0xa4, 0x50, 0x1d, 0xdf, 0xe9, 0x2f, 0x46, 0x68, 0x1b, 0x20, 0xa0,
+];
+
+/// An EIP-2718 typed transaction envelope.
+///
+/// Wraps three transaction formats: untyped legacy transactions, and the
+/// `0x01` (EIP-2930) and `0x02` (EIP-1559) typed envelopes. The builder
+/// produces the legacy and EIP-1559 formats; it does not build EIP-2930
+/// transactions. This lets callers target chains and wallets that don't
+/// accept the 1559 envelope.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum TypedTransaction {
+    Legacy(LegacyTransaction),
+    Eip2930(Eip2930Transaction),
+    Eip1559(EVMTransaction),
+}
+
+impl TypedTransaction {
+    pub fn build_for_signing(&self) -> Vec<u8> {
+        match self {
+            Self::Legacy(tx) => tx.build_for_signing(),
+            Self::Eip2930(tx) => tx.build_for_signing(),
+            Self::Eip1559(tx) => tx.build_for_signing(),
+        }
+    }
+
+    pub fn build_with_signature(&self, signature: &Signature) -> Vec<u8> {
+        match self {
+            Self::Legacy(tx) => tx.build_with_signature(signature),
+            Self::Eip2930(tx) => tx.build_with_signature(signature),
+            Self::Eip1559(tx) => tx.build_with_signature(signature),
+        }
+    }
+
+    /// Reconstructs a transaction from its raw RLP-encoded bytes, as
+    /// produced by [`Self::build_for_signing`] or [`Self::build_with_signature`].
+    ///
+    /// The leading type byte (`0x01` for EIP-2930, `0x02` for EIP-1559, or
+    /// an RLP list header for legacy) selects the variant. When the bytes
+    /// carry a trailing `v, r, s`, the recovered [`Signature`] is returned
+    /// alongside the transaction.
+    pub fn from_bytes(bytes: &[u8]) -> Result<(Self, Option<Signature>), DecodeError> {
+        let first_byte = *bytes.first().ok_or(DecodeError::Empty)?;
+
+        match first_byte {
+            EIP_2930_TYPE => decode_eip2930(&bytes[1..]),
+            EIP_1559_TYPE => decode_eip1559(&bytes[1..]),
+            byte if byte >= 0xc0 => decode_legacy(bytes),
+            byte => Err(DecodeError::UnknownType(byte)),
+        }
+    }
+
+    /// Recovers the address that produced `signature` over this
+    /// transaction's signing payload.
+    pub fn recover_signer(&self, signature: &Signature) -> Result<Address, RecoverError> {
+        let recovery_id = self.normalize_recovery_id(signature.v)?;
+        let message_hash = keccak256(&self.build_for_signing());
+
+        recover_address(&message_hash, recovery_id, &signature.r, &signature.s)
+    }
+
+    /// Normalizes `v` into a 0/1 recovery id, accounting for the legacy
+    /// EIP-155 encoding (`35 + chain_id * 2 + recovery_id`) as well as the
+    /// raw parity used by typed transactions.
+    fn normalize_recovery_id(&self, v: u64) -> Result<RecoveryId, RecoverError> {
+        let parity = match self {
+            Self::Legacy(tx) => {
+                let eip155_offset = tx.chain_id.saturating_mul(2).saturating_add(35);
+                if v >= eip155_offset {
+                    v - eip155_offset
+                } else if v >= 27 {
+                    v - 27
+                } else {
+                    v
+                }
+            }
+            Self::Eip2930(_) | Self::Eip1559(_) => v,
+        };
+
+        RecoveryId::from_i32(parity as i32).map_err(|_| RecoverError::InvalidRecoveryId(v))
+    }
+}
+
+fn left_pad_32(bytes: &[u8]) -> Result<[u8; 32], RecoverError> {
+    if bytes.len() > 32 {
+        return Err(RecoverError::InvalidSignatureLength);
+    }
+
+    let mut padded = [0u8; 32];
+    padded[32 - bytes.len()..].copy_from_slice(bytes);
+    Ok(padded)
+}
+
+fn recover_address(
+    message_hash: &[u8; 32],
+    recovery_id: RecoveryId,
+    r: &[u8],
+    s: &[u8],
+) -> Result<Address, RecoverError> {
+    let r = left_pad_32(r)?;
+    let s = left_pad_32(s)?;
+
+    if s > SECP256K1N_HALF {
+        return Err(RecoverError::HighS);
+    }
+
+    let mut compact_signature = [0u8; 64];
+    compact_signature[..32].copy_from_slice(&r);
+    compact_signature[32..].copy_from_slice(&s);
+
+    let signature = RecoverableSignature::from_compact(&compact_signature, recovery_id)?;
+    let message = Message::from_digest(*message_hash);
+
+    let public_key = Secp256k1::verification_only().recover_ecdsa(&message, &signature)?;
+    let uncompressed = public_key.serialize_uncompressed();
+
+    // Drop the 0x04 prefix byte, hash the remaining 64-byte public key, and
+    // keep the last 20 bytes, mirroring how Ethereum derives addresses.
+    let hash = keccak256(&uncompressed[1..]);
+
+    Ok(hash[12..]
+        .try_into()
+        .expect("keccak256 output is 32 bytes, slice is fixed at 20"))
+}
+
+fn decode_to(rlp: &Rlp) -> Result<Option<Address>, DecodeError> {
+    let data = rlp.data()?;
+    if data.is_empty() {
+        Ok(None)
+    } else {
+        let to: Address = data.to_vec().try_into().map_err(|_| DecodeError::InvalidLength)?;
+        Ok(Some(to))
+    }
+}
+
+fn decode_access_list(rlp: &Rlp) -> Result<AccessList, DecodeError> {
+    rlp.iter()
+        .map(|entry| {
+            let address: Vec<u8> = entry.at(0)?.data()?.to_vec();
+            let address: Address = address.try_into().map_err(|_| DecodeError::InvalidLength)?;
+
+            let storage_keys = entry
+                .at(1)?
+                .iter()
+                .map(|key| {
+                    let key: Vec<u8> = key.data()?.to_vec();
+                    key.try_into().map_err(|_| DecodeError::InvalidLength)
+                })
+                .collect::<Result<Vec<[u8; 32]>, DecodeError>>()?;
+
+            Ok((address, storage_keys))
+        })
+        .collect()
+}
+
+/// Decodes the trailing `v, r, s` at `offset`, if present.
+fn decode_signature(rlp: &Rlp, offset: usize) -> Result<Option<Signature>, DecodeError> {
+    if rlp.item_count()? <= offset {
+        return Ok(None);
+    }
+
+    Ok(Some(Signature {
+        v: rlp.val_at(offset)?,
+        r: rlp.val_at(offset + 1)?,
+        s: rlp.val_at(offset + 2)?,
+    }))
+}
+
+fn decode_eip2930(bytes: &[u8]) -> Result<(TypedTransaction, Option<Signature>), DecodeError> {
+    let rlp = Rlp::new(bytes);
+
+    let tx = Eip2930Transaction {
+        chain_id: rlp.val_at(0)?,
+        nonce: rlp.val_at(1)?,
+        gas_price: rlp.val_at(2)?,
+        gas_limit: rlp.val_at(3)?,
+        to: decode_to(&rlp.at(4)?)?,
+        value: rlp.val_at(5)?,
+        input: rlp.val_at(6)?,
+        access_list: decode_access_list(&rlp.at(7)?)?,
+    };
+
+    let signature = decode_signature(&rlp, 8)?;
+
+    Ok((TypedTransaction::Eip2930(tx), signature))
+}
+
+fn decode_eip1559(bytes: &[u8]) -> Result<(TypedTransaction, Option<Signature>), DecodeError> {
+    let rlp = Rlp::new(bytes);
+
+    let tx = EVMTransaction {
+        chain_id: rlp.val_at(0)?,
+        nonce: rlp.val_at(1)?,
+        max_priority_fee_per_gas: rlp.val_at(2)?,
+        max_fee_per_gas: rlp.val_at(3)?,
+        gas_limit: rlp.val_at(4)?,
+        to: decode_to(&rlp.at(5)?)?,
+        value: rlp.val_at(6)?,
+        input: rlp.val_at(7)?,
+        access_list: decode_access_list(&rlp.at(8)?)?,
+    };
+
+    let signature = decode_signature(&rlp, 9)?;
+
+    Ok((TypedTransaction::Eip1559(tx), signature))
+}
+
+fn decode_legacy(bytes: &[u8]) -> Result<(TypedTransaction, Option<Signature>), DecodeError> {
+    let rlp = Rlp::new(bytes);
+
+    let nonce = rlp.val_at(0)?;
+    let gas_price = rlp.val_at(1)?;
+    let gas_limit = rlp.val_at(2)?;
+    let to = decode_to(&rlp.at(3)?)?;
+    let value = rlp.val_at(4)?;
+    let input = rlp.val_at(5)?;
+
+    let (chain_id, signature) = if rlp.item_count()? > 6 {
+        let v: u64 = rlp.val_at(6)?;
+        let r: Vec<u8> = rlp.val_at(7)?;
+        let s: Vec<u8> = rlp.val_at(8)?;
+
+        if r.is_empty() && s.is_empty() {
+            // Unsigned EIP-155 signing payload: v holds the chain_id, r/s are 0.
+            (v, None)
+        } else {
+            // Signed legacy transaction: recover chain_id from v per EIP-155.
+            let chain_id = if v >= 35 { (v - 35) / 2 } else { 0 };
+            (chain_id, Some(Signature { v, r, s }))
+        }
+    } else {
+        (0, None)
+    };
+
+    let tx = LegacyTransaction {
+        chain_id,
+        nonce,
+        gas_price,
+        gas_limit,
+        to,
+        value,
+        input,
+    };
+
+    Ok((TypedTransaction::Legacy(tx), signature))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evm::utils::parse_eth_address;
+
+    fn sample_eip1559() -> EVMTransaction {
+        EVMTransaction {
+            chain_id: 1,
+            nonce: 7,
+            to: Some(parse_eth_address("d8dA6BF26964aF9D7eEd9e03E53415D37aA96045")),
+            value: 10_000_000_000_000_000,
+            input: vec![],
+            gas_limit: 21_000,
+            max_fee_per_gas: 30_000_000_000,
+            max_priority_fee_per_gas: 1_500_000_000,
+            access_list: vec![],
+        }
+    }
+
+    // r/s/expected address were produced by signing this exact EIP-1559
+    // `build_for_signing` payload with a fixed, known private key.
+    fn sample_signature() -> Signature {
+        Signature {
+            v: 1,
+            r: hex::decode("8b42e43c3e8640cd89a766af7f162a99a2d49da20cba9a09c64934ac27922ad9")
+                .unwrap(),
+            s: hex::decode("21001e94cbded4ef209cedb9a12dad6df2308aab568127ac0305bff2101a4eae")
+                .unwrap(),
+        }
+    }
+
+    #[test]
+    fn eip1559_round_trips_through_decode() {
+        let tx = TypedTransaction::Eip1559(sample_eip1559());
+        let signature = sample_signature();
+        let bytes = tx.build_with_signature(&signature);
+
+        let (decoded, decoded_signature) = TypedTransaction::from_bytes(&bytes).unwrap();
+
+        assert_eq!(decoded, tx);
+        assert_eq!(decoded_signature, Some(signature));
+    }
+
+    #[test]
+    fn eip2930_round_trips_through_decode() {
+        let access_list: AccessList = vec![(
+            parse_eth_address("d8dA6BF26964aF9D7eEd9e03E53415D37aA96045"),
+            vec![[1u8; 32], [2u8; 32]],
+        )];
+
+        let tx = TypedTransaction::Eip2930(Eip2930Transaction {
+            chain_id: 1,
+            nonce: 3,
+            gas_price: 20_000_000_000,
+            gas_limit: 21_000,
+            to: Some(parse_eth_address("d8dA6BF26964aF9D7eEd9e03E53415D37aA96045")),
+            value: 0,
+            input: vec![0xde, 0xad, 0xbe, 0xef],
+            access_list,
+        });
+        let signature = sample_signature();
+        let bytes = tx.build_with_signature(&signature);
+
+        let (decoded, decoded_signature) = TypedTransaction::from_bytes(&bytes).unwrap();
+
+        assert_eq!(decoded, tx);
+        assert_eq!(decoded_signature, Some(signature));
+    }
+
+    #[test]
+    fn legacy_round_trips_through_decode() {
+        let tx = TypedTransaction::Legacy(LegacyTransaction {
+            chain_id: 1,
+            nonce: 9,
+            gas_price: 20_000_000_000,
+            gas_limit: 21_000,
+            to: Some(parse_eth_address("d8dA6BF26964aF9D7eEd9e03E53415D37aA96045")),
+            value: 1_000_000_000_000_000_000,
+            input: vec![],
+        });
+        // Raw recovery id; build_with_signature folds it into the EIP-155 `v`.
+        let signature = Signature {
+            v: 0,
+            r: vec![1u8; 32],
+            s: vec![2u8; 32],
+        };
+        let bytes = tx.build_with_signature(&signature);
+
+        let (decoded, decoded_signature) = TypedTransaction::from_bytes(&bytes).unwrap();
+
+        assert_eq!(decoded, tx);
+        // The decoded signature carries the folded EIP-155 `v`, not the raw recovery id.
+        assert_eq!(
+            decoded_signature,
+            Some(Signature {
+                v: 37,
+                r: vec![1u8; 32],
+                s: vec![2u8; 32],
+            })
+        );
+    }
+
+    #[test]
+    fn recovers_known_signer_address() {
+        let tx = TypedTransaction::Eip1559(sample_eip1559());
+        let signature = sample_signature();
+
+        let recovered = tx.recover_signer(&signature).unwrap();
+
+        assert_eq!(
+            recovered,
+            parse_eth_address("95f398a1a093c8175d1cdd8c9ed426466b86c8fc")
+        );
+    }
+}
@@ -5,7 +5,7 @@ pub type Address = [u8; 20];
 
 pub type AccessList = Vec<(Address, Vec<[u8; 32]>)>;
 
-#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub struct Signature {
     pub v: u64,
     pub r: Vec<u8>,
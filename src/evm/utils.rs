@@ -0,0 +1,17 @@
+use sha3::{Digest, Keccak256};
+
+use super::types::Address;
+
+/// Parses a hex-encoded (no `0x` prefix) string into an Ethereum address.
+pub fn parse_eth_address(address: &str) -> Address {
+    let bytes = hex::decode(address).expect("address should be valid hex");
+    bytes
+        .try_into()
+        .expect("address should decode to 20 bytes")
+}
+
+/// Hashes `data` with keccak256, the hash function used throughout the
+/// Ethereum transaction and account model.
+pub fn keccak256(data: &[u8]) -> [u8; 32] {
+    Keccak256::digest(data).into()
+}
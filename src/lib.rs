@@ -0,0 +1,3 @@
+pub mod constants;
+pub mod evm;
+pub mod transaction_builder;